@@ -0,0 +1,157 @@
+use crate::error::DecimalCqlError;
+use num_bigint::BigInt;
+use scylla::_macro_internal::{
+    CellWriter, ColumnType, DeserializeValue, SerializeValue, WrittenCellProof,
+};
+use scylla::cluster::metadata::NativeType;
+use scylla::deserialize::{DeserializationError, FrameSlice, TypeCheckError};
+use scylla::serialize::SerializationError;
+use scylla::value::CqlDecimal;
+
+const SCALE_BYTES: usize = 4;
+
+/// Arbitrary-precision counterpart to [`crate::DecimalCql`] for CQL `DECIMAL`
+/// values whose unscaled mantissa does not fit in an `i128`, and therefore
+/// cannot be represented by `rust_decimal::Decimal` (limited to a 96-bit
+/// unscaled value). Keeps the full signed mantissa in a `BigInt` instead.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BigDecimalCql {
+    unscaled: BigInt,
+    scale: i32,
+}
+
+impl BigDecimalCql {
+    pub fn new(unscaled: BigInt, scale: i32) -> Self {
+        Self { unscaled, scale }
+    }
+
+    /// The unscaled integer value, i.e. the mantissa before applying `scale`.
+    pub fn unscaled_value(&self) -> &BigInt {
+        &self.unscaled
+    }
+
+    /// The number of digits to the right of the decimal point.
+    pub fn scale(&self) -> i32 {
+        self.scale
+    }
+}
+
+impl From<(BigInt, i32)> for BigDecimalCql {
+    fn from((unscaled, scale): (BigInt, i32)) -> Self {
+        Self::new(unscaled, scale)
+    }
+}
+
+/// Implements `SerializeValue` for `BigDecimalCql` by writing the scale
+/// followed by the minimal-length signed big-endian two's-complement
+/// mantissa, matching the on-wire convention used by `DecimalCql`.
+impl SerializeValue for BigDecimalCql {
+    fn serialize<'b>(
+        &self,
+        _typ: &ColumnType,
+        writer: CellWriter<'b>,
+    ) -> Result<WrittenCellProof<'b>, SerializationError> {
+        let mantissa_bytes = self.unscaled.to_signed_bytes_be();
+        let cql_decimal: CqlDecimal =
+            CqlDecimal::from_signed_be_bytes_and_exponent(mantissa_bytes, self.scale);
+        cql_decimal.serialize(_typ, writer)
+    }
+}
+
+/// Implements deserialization for `BigDecimalCql`, building the `BigInt`
+/// from the entire signed mantissa slice so that values too large for
+/// `DecimalCql` are preserved exactly instead of being truncated.
+impl<'frame, 'metadata> DeserializeValue<'frame, 'metadata> for BigDecimalCql {
+    fn type_check(typ: &ColumnType) -> Result<(), TypeCheckError> {
+        if !matches!(typ, ColumnType::Native(NativeType::Decimal)) {
+            let typ_info: String = format!("Expected {:?}, got {:?}", NativeType::Decimal, typ);
+            return Err(TypeCheckError::new(DecimalCqlError::MismatchedType(
+                typ_info,
+            )));
+        }
+        Ok(())
+    }
+
+    fn deserialize(
+        _typ: &'metadata ColumnType<'metadata>,
+        frame: Option<FrameSlice<'frame>>,
+    ) -> Result<BigDecimalCql, DeserializationError> {
+        let fs: FrameSlice =
+            frame.ok_or_else(|| DeserializationError::new(DecimalCqlError::FrameHasNoSlice()))?;
+        let (scale, unscaled) = extract_scale_and_big_mantissa_from_slice(fs.as_slice())
+            .map_err(|e| DeserializationError::new(e))?;
+        Ok(BigDecimalCql { unscaled, scale })
+    }
+}
+
+/// The first 4 bytes are the scale (`i32`), and the remaining bytes are the
+/// full signed big-endian two's-complement mantissa, kept in its entirety
+/// as a `BigInt` instead of being clamped to 16 bytes.
+///
+/// # Arguments
+/// - `bytes`: A byte slice derived from a `FrameSlice`.
+///
+/// # Returns
+/// - `Ok((i32, BigInt))`: The scale and unscaled mantissa.
+/// - `Err(DecimalCqlError)`
+fn extract_scale_and_big_mantissa_from_slice(
+    bytes: &[u8],
+) -> Result<(i32, BigInt), DecimalCqlError> {
+    if bytes.len() < SCALE_BYTES {
+        return Err(DecimalCqlError::ByteArrayTooShort(bytes.len()));
+    }
+    let scale: i32 = i32::from_be_bytes(
+        bytes[0..SCALE_BYTES]
+            .try_into()
+            .expect("Is safe because bytes length have been checked"),
+    );
+    let mantissa_bytes: &[u8] = &bytes[SCALE_BYTES..];
+    let unscaled: BigInt = if mantissa_bytes.is_empty() {
+        BigInt::from(0)
+    } else {
+        BigInt::from_signed_bytes_be(mantissa_bytes)
+    };
+    Ok((scale, unscaled))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_big_decimal_cql_serialize() {
+        let value = BigDecimalCql::new(BigInt::from(-1), 0);
+        let mut buffer = Vec::new();
+        let writer = CellWriter::new(&mut buffer);
+        value
+            .serialize(&ColumnType::Native(NativeType::Decimal), writer)
+            .unwrap();
+        // length(5) + scale(4) + minimal 1-byte mantissa (0xFF = -1)
+        assert_eq!(buffer, [0, 0, 0, 5, 0, 0, 0, 0, 0xFF]);
+    }
+
+    #[test]
+    fn test_extract_scale_and_big_mantissa_from_slice_beyond_i128() {
+        // 2^127 overflows i128::MAX (2^127 - 1), the case DecimalCql cannot represent.
+        let huge = BigInt::from(2).pow(127);
+        let mantissa_bytes = huge.to_signed_bytes_be();
+        let mut bytes = vec![0, 0, 0, 0];
+        bytes.extend_from_slice(&mantissa_bytes);
+
+        let result = extract_scale_and_big_mantissa_from_slice(&bytes).unwrap();
+        assert_eq!(result, (0, huge));
+    }
+
+    #[test]
+    fn test_extract_scale_and_big_mantissa_from_slice_empty_mantissa() {
+        let bytes = &[0, 0, 0, 2];
+        let result = extract_scale_and_big_mantissa_from_slice(bytes).unwrap();
+        assert_eq!(result, (2, BigInt::from(0)));
+    }
+
+    #[test]
+    fn test_big_decimal_cql_no_frame() {
+        let result = BigDecimalCql::deserialize(&ColumnType::Native(NativeType::Decimal), None);
+        assert!(result.is_err());
+    }
+}
@@ -7,6 +7,10 @@ pub enum DecimalCqlError {
     FrameHasNoSlice(),
     ByteArrayTooShort(usize),
     InvalidMantissaConversion(),
+    MantissaOverflow(usize),
+    MantissaOutOfDecimalRange(i128),
+    ScaleOutOfRange(i32),
+    Parse(String),
 }
 
 impl fmt::Display for DecimalCqlError {
@@ -24,6 +28,28 @@ impl fmt::Display for DecimalCqlError {
             DecimalCqlError::InvalidMantissaConversion() => {
                 write!(f, "Could not convert array to i128")
             }
+            DecimalCqlError::MantissaOverflow(len) => {
+                write!(
+                    f,
+                    "Mantissa of {} bytes does not fit in an i128; use BigDecimalCql for values this large",
+                    len
+                )
+            }
+            DecimalCqlError::MantissaOutOfDecimalRange(mantissa) => {
+                write!(
+                    f,
+                    "Mantissa {} does not fit in rust_decimal::Decimal's 96-bit unscaled value; use BigDecimalCql for values this large",
+                    mantissa
+                )
+            }
+            DecimalCqlError::ScaleOutOfRange(scale) => {
+                write!(
+                    f,
+                    "Scale {} is negative or exceeds rust_decimal's supported range and could not be rescaled",
+                    scale
+                )
+            }
+            DecimalCqlError::Parse(msg) => write!(f, "Could not parse decimal: {}", msg),
         }
     }
 }
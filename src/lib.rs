@@ -1,4 +1,9 @@
 mod error;
+#[cfg(feature = "big-decimal")]
+mod big_decimal;
+
+#[cfg(feature = "big-decimal")]
+pub use big_decimal::BigDecimalCql;
 
 use crate::error::DecimalCqlError;
 use rust_decimal::Decimal;
@@ -9,7 +14,9 @@ use scylla::cluster::metadata::NativeType;
 use scylla::deserialize::{DeserializationError, FrameSlice, TypeCheckError};
 use scylla::serialize::SerializationError;
 use scylla::value::CqlDecimal;
+use std::convert::TryFrom;
 use std::ops::Deref;
+use std::str::FromStr;
 
 const SCALE_BYTES: usize = 4;
 const PADDING_BYTES: usize = 16;
@@ -23,6 +30,39 @@ impl From<Decimal> for DecimalCql {
     }
 }
 
+/// Parses a `DecimalCql` from a decimal string, e.g. `"1.12345678956783"`,
+/// via `Decimal::from_str`. Lets callers bind values straight from config
+/// files or JSON without an intermediate `Decimal` dance.
+impl FromStr for DecimalCql {
+    type Err = DecimalCqlError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Decimal::from_str(s)
+            .map(DecimalCql)
+            .map_err(|e| DecimalCqlError::Parse(e.to_string()))
+    }
+}
+
+impl TryFrom<&str> for DecimalCql {
+    type Error = DecimalCqlError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// Converts an `f64` to a `DecimalCql` via `Decimal::try_from`, which fails
+/// for `NaN`, infinities, and magnitudes `Decimal` cannot represent.
+impl TryFrom<f64> for DecimalCql {
+    type Error = DecimalCqlError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Decimal::try_from(value)
+            .map(DecimalCql)
+            .map_err(|e| DecimalCqlError::Parse(e.to_string()))
+    }
+}
+
 /// Transparent access to the inner `Decimal` value within `DecimalCql` by Dereferencing.
 ///
 /// # Examples
@@ -66,13 +106,81 @@ impl SerializeValue for DecimalCql {
     ) -> Result<WrittenCellProof<'b>, SerializationError> {
         let mantissa_bytes = self.0.mantissa().to_be_bytes();
         let cql_decimal: CqlDecimal = CqlDecimal::from_signed_be_bytes_and_exponent(
-            mantissa_bytes.to_vec(),
+            minimal_two_complement_bytes(&mantissa_bytes).to_vec(),
             self.0.scale() as i32,
         );
         cql_decimal.serialize(_typ, writer)
     }
 }
 
+/// Strips leading bytes from a big-endian two's-complement integer that are
+/// pure sign extension, returning the shortest equivalent byte slice.
+///
+/// At least one byte is always kept, and the retained leading byte's top bit
+/// still matches the value's sign, so the result round-trips through
+/// `i128::from_be_bytes` after sign-extending back to full width.
+///
+/// # Arguments
+/// - `bytes`: A big-endian two's-complement byte array (e.g. `i128::to_be_bytes()`).
+///
+/// # Returns
+/// - The minimal-length leading-sign-extension-stripped slice.
+fn minimal_two_complement_bytes(bytes: &[u8]) -> &[u8] {
+    let mut start = 0;
+    while start + 1 < bytes.len() {
+        let is_positive_padding = bytes[start] == 0x00 && bytes[start + 1] & 0x80 == 0x00;
+        let is_negative_padding = bytes[start] == 0xFF && bytes[start + 1] & 0x80 == 0x80;
+        if is_positive_padding || is_negative_padding {
+            start += 1;
+        } else {
+            break;
+        }
+    }
+    &bytes[start..]
+}
+
+impl DecimalCql {
+    /// Deserializes a `CqlDecimal` frame into a `DecimalCql`, like
+    /// [`DeserializeValue::deserialize`], but lets the caller pick the
+    /// [`RoundingMode`] used when the CQL scale exceeds
+    /// `rust_decimal`'s 28-scale limit.
+    ///
+    /// # Errors
+    /// - Returns `DeserializationError` if the frame is empty, the data
+    ///   cannot be parsed, or the scale is so large it cannot be reduced
+    ///   to a supported scale (see [`DecimalCqlError::ScaleOutOfRange`]).
+    pub fn deserialize_with_rounding<'frame, 'metadata>(
+        _typ: &'metadata ColumnType<'metadata>,
+        frame: Option<FrameSlice<'frame>>,
+        rounding: RoundingMode,
+    ) -> Result<DecimalCql, DeserializationError> {
+        let fs: FrameSlice =
+            frame.ok_or_else(|| DeserializationError::new(DecimalCqlError::FrameHasNoSlice()))?;
+        let (scale, mantissa): (i32, i128) = extract_scale_and_mantissa_from_slice(fs.as_slice())
+            .map_err(|e| DeserializationError::new(e))?;
+        let (mantissa, scale): (i128, u32) = rescale_to_supported_range(mantissa, scale, rounding)
+            .map_err(|e| DeserializationError::new(e))?;
+        let decimal: Decimal = decimal_from_i128_with_scale(mantissa, scale)
+            .map_err(|e| DeserializationError::new(e))?;
+        Ok(DecimalCql(decimal))
+    }
+}
+
+/// The largest unscaled value `rust_decimal::Decimal` can hold (`2^96 - 1`),
+/// i.e. `Decimal::MAX.mantissa()`.
+const MAX_DECIMAL_MANTISSA: i128 = 79_228_162_514_264_337_593_543_950_335;
+
+/// Builds a `Decimal` from a mantissa and scale, guarding against the panic
+/// `Decimal::from_i128_with_scale` would otherwise raise for a mantissa that
+/// fits in an `i128` but overflows `Decimal`'s 96-bit unscaled value (e.g. a
+/// 13-byte encoding of `2^100`).
+fn decimal_from_i128_with_scale(mantissa: i128, scale: u32) -> Result<Decimal, DecimalCqlError> {
+    if !(-MAX_DECIMAL_MANTISSA..=MAX_DECIMAL_MANTISSA).contains(&mantissa) {
+        return Err(DecimalCqlError::MantissaOutOfDecimalRange(mantissa));
+    }
+    Ok(Decimal::from_i128_with_scale(mantissa, scale))
+}
+
 /// Implements deserialization for `DecimalCql` to deserialize a `CqlDecimal` to a
 /// `DecimalCql` with an inner `Decimal`
 ///
@@ -95,49 +203,145 @@ impl<'frame, 'metadata> DeserializeValue<'frame, 'metadata> for DecimalCql {
     }
 
     fn deserialize(
-        _typ: &'metadata ColumnType<'metadata>,
+        typ: &'metadata ColumnType<'metadata>,
         frame: Option<FrameSlice<'frame>>,
     ) -> Result<DecimalCql, DeserializationError> {
-        let fs: FrameSlice =
-            frame.ok_or_else(|| DeserializationError::new(DecimalCqlError::FrameHasNoSlice()))?;
-        let (scale, mantissa): (u32, i128) = extract_scale_and_mantissa_from_slice(fs.as_slice())
-            .map_err(|e| DeserializationError::new(e))?;
-        let decimal: Decimal = Decimal::from_i128_with_scale(mantissa, scale);
-        Ok(DecimalCql(decimal))
+        DecimalCql::deserialize_with_rounding(typ, frame, RoundingMode::default())
+    }
+}
+
+/// The maximum scale `rust_decimal::Decimal` can represent.
+const MAX_SUPPORTED_SCALE: u32 = 28;
+
+/// Rounding policy applied when a CQL scale exceeds [`MAX_SUPPORTED_SCALE`].
+///
+/// Cassandra's `DECIMAL` scale is an unconstrained `i32`, while
+/// `rust_decimal::Decimal` only supports scales from 0 to 28. `HalfEven`
+/// (banker's rounding) is the default because it matches `rust_decimal`'s
+/// own rounding behavior (e.g. `Decimal::round_dp`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundingMode {
+    /// Round half away from zero.
+    HalfUp,
+    /// Round half to the nearest even digit (banker's rounding).
+    #[default]
+    HalfEven,
+    /// Round towards negative infinity.
+    Down,
+    /// Round towards zero, discarding any remainder.
+    Truncate,
+}
+
+impl RoundingMode {
+    fn round(self, mantissa: i128, divisor: i128) -> i128 {
+        let quotient: i128 = mantissa / divisor;
+        let remainder: i128 = mantissa % divisor;
+        if remainder == 0 {
+            return quotient;
+        }
+        match self {
+            RoundingMode::Truncate => quotient,
+            RoundingMode::Down => {
+                if mantissa < 0 {
+                    quotient - 1
+                } else {
+                    quotient
+                }
+            }
+            RoundingMode::HalfUp => {
+                if remainder.unsigned_abs() * 2 >= divisor.unsigned_abs() {
+                    quotient + mantissa.signum()
+                } else {
+                    quotient
+                }
+            }
+            RoundingMode::HalfEven => {
+                let doubled_remainder: u128 = remainder.unsigned_abs() * 2;
+                let divisor_abs: u128 = divisor.unsigned_abs();
+                if doubled_remainder > divisor_abs
+                    || (doubled_remainder == divisor_abs && quotient % 2 != 0)
+                {
+                    quotient + mantissa.signum()
+                } else {
+                    quotient
+                }
+            }
+        }
+    }
+}
+
+/// Reduces `(mantissa, scale)` to [`MAX_SUPPORTED_SCALE`] when `scale` exceeds
+/// it, dividing the mantissa by the appropriate power of ten under `rounding`.
+///
+/// `rust_decimal::Decimal` has no representation for a negative scale, so a
+/// negative `scale` is rejected outright with its real signed value rather
+/// than being reinterpreted as an enormous positive one.
+///
+/// # Returns
+/// - `Ok((i128, u32))` unchanged if `0 <= scale <= MAX_SUPPORTED_SCALE`, otherwise rescaled.
+/// - `Err(DecimalCqlError::ScaleOutOfRange)` if `scale` is negative, or so
+///   large the divisor itself overflows `i128`.
+fn rescale_to_supported_range(
+    mantissa: i128,
+    scale: i32,
+    rounding: RoundingMode,
+) -> Result<(i128, u32), DecimalCqlError> {
+    if scale < 0 {
+        return Err(DecimalCqlError::ScaleOutOfRange(scale));
+    }
+    let scale: u32 = scale as u32;
+    if scale <= MAX_SUPPORTED_SCALE {
+        return Ok((mantissa, scale));
     }
+    let excess: u32 = scale - MAX_SUPPORTED_SCALE;
+    let divisor: i128 = 10i128
+        .checked_pow(excess)
+        .ok_or(DecimalCqlError::ScaleOutOfRange(scale as i32))?;
+    Ok((rounding.round(mantissa, divisor), MAX_SUPPORTED_SCALE))
 }
 
-/// The first 4 bytes are the scale (`u32`), and the remaining bytes as
-/// the mantissa (`i128`). Pads the mantissa to 16 bytes if needed.
+/// The first 4 bytes are the scale (a signed `i32`, matching Cassandra's
+/// unconstrained `DECIMAL` scale), and the remaining bytes are the mantissa
+/// (`i128`), encoded as a minimal-length signed big-endian two's complement
+/// integer by the writing client. Sign-extends the mantissa to 16 bytes if
+/// needed.
 ///
 /// # Arguments
 /// - `bytes`: A byte slice derived from a `FrameSlice`.
 ///
 /// # Returns
-/// - `Ok((u32, i128))`: The scale and mantissa.
+/// - `Ok((i32, i128))`: The scale and mantissa.
 /// - `Err(DecimalCqlError)`
 
-fn extract_scale_and_mantissa_from_slice(bytes: &[u8]) -> Result<(u32, i128), DecimalCqlError> {
+fn extract_scale_and_mantissa_from_slice(bytes: &[u8]) -> Result<(i32, i128), DecimalCqlError> {
     if bytes.len() < SCALE_BYTES {
         return Err(DecimalCqlError::ByteArrayTooShort(bytes.len()));
     }
-    let scale: u32 = u32::from_be_bytes(
+    let scale: i32 = i32::from_be_bytes(
         bytes[0..SCALE_BYTES]
             .try_into()
             .expect("Is safe because bytes length have been checked"),
     );
     let mantissa_bytes: &[u8] = &bytes[SCALE_BYTES..];
-    let mantissa: i128 = if mantissa_bytes.len() >= PADDING_BYTES {
-        // If mantissa_bytes has 16 or more bytes, truncate to the first 16 bytes
+    let mantissa: i128 = if mantissa_bytes.len() > PADDING_BYTES {
+        // The unscaled value does not fit in an i128; bail out rather than
+        // silently dropping the least-significant bytes. Callers that need
+        // full fidelity for values this large should use `BigDecimalCql`.
+        return Err(DecimalCqlError::MantissaOverflow(mantissa_bytes.len()));
+    } else if mantissa_bytes.len() == PADDING_BYTES {
         i128::from_be_bytes(
-            mantissa_bytes[0..PADDING_BYTES]
+            mantissa_bytes
                 .try_into()
                 .map_err(|_| DecimalCqlError::InvalidMantissaConversion())?,
         )
+    } else if mantissa_bytes.is_empty() {
+        0
     } else {
-        // Otherwise, pad the mantissa_bytes to 16 bytes
+        // Otherwise, sign-extend the mantissa_bytes to 16 bytes: a set top
+        // bit means the value is negative, so pad with 0xFF instead of 0x00.
+        let sign_byte: u8 = if mantissa_bytes[0] & 0x80 != 0 { 0xFF } else { 0 };
         let padding_length: usize = PADDING_BYTES - mantissa_bytes.len();
-        let mut padded_bytes: Vec<u8> = vec![0; padding_length];
+        let mut padded_bytes: Vec<u8> = vec![sign_byte; padding_length];
         padded_bytes.extend_from_slice(mantissa_bytes);
         i128::from_be_bytes(
             padded_bytes
@@ -149,10 +353,102 @@ fn extract_scale_and_mantissa_from_slice(bytes: &[u8]) -> Result<(u32, i128), De
     Ok((scale, mantissa))
 }
 
+/// Cell-level wrapper around `DecimalCql` that distinguishes a CQL `NULL`
+/// from an `UNSET` value, mirroring the distinction the scylla serialization
+/// layer makes at the cell level (`SerializeValue`/`CellWriter`).
+///
+/// Binding a `DecimalCqlValue::Unset` leaves the column untouched on
+/// upsert, `DecimalCqlValue::Null` clears it, and `DecimalCqlValue::Value`
+/// writes the encoded decimal as usual.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DecimalCqlValue {
+    Null,
+    Unset,
+    Value(DecimalCql),
+}
+
+impl From<DecimalCql> for DecimalCqlValue {
+    fn from(value: DecimalCql) -> Self {
+        DecimalCqlValue::Value(value)
+    }
+}
+
+impl From<Option<DecimalCql>> for DecimalCqlValue {
+    fn from(value: Option<DecimalCql>) -> Self {
+        match value {
+            Some(v) => DecimalCqlValue::Value(v),
+            None => DecimalCqlValue::Null,
+        }
+    }
+}
+
+impl SerializeValue for DecimalCqlValue {
+    fn serialize<'b>(
+        &self,
+        typ: &ColumnType,
+        writer: CellWriter<'b>,
+    ) -> Result<WrittenCellProof<'b>, SerializationError> {
+        match self {
+            DecimalCqlValue::Null => Ok(writer.set_null()),
+            DecimalCqlValue::Unset => Ok(writer.set_unset()),
+            DecimalCqlValue::Value(decimal) => decimal.serialize(typ, writer),
+        }
+    }
+}
+
+/// Deserializes a `DecimalCqlValue`, tolerating an absent frame slice by
+/// yielding `Null` instead of erroring with `FrameHasNoSlice`, as `UNSET`
+/// is a write-only concept and never appears when reading a row back.
+impl<'frame, 'metadata> DeserializeValue<'frame, 'metadata> for DecimalCqlValue {
+    fn type_check(typ: &ColumnType) -> Result<(), TypeCheckError> {
+        DecimalCql::type_check(typ)
+    }
+
+    fn deserialize(
+        typ: &'metadata ColumnType<'metadata>,
+        frame: Option<FrameSlice<'frame>>,
+    ) -> Result<DecimalCqlValue, DeserializationError> {
+        match frame {
+            None => Ok(DecimalCqlValue::Null),
+            Some(_) => DecimalCql::deserialize(typ, frame).map(DecimalCqlValue::Value),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_decimal_cql_from_str() {
+        let wrapper: DecimalCql = "1.12345678956783".parse().unwrap();
+        assert_eq!(*wrapper, Decimal::from_str("1.12345678956783").unwrap());
+    }
+
+    #[test]
+    fn test_decimal_cql_from_str_invalid() {
+        let result: Result<DecimalCql, _> = "not-a-decimal".parse();
+        assert!(matches!(result, Err(DecimalCqlError::Parse(_))));
+    }
+
+    #[test]
+    fn test_decimal_cql_try_from_str() {
+        let wrapper = DecimalCql::try_from("123.45").unwrap();
+        assert_eq!(*wrapper, Decimal::new(12345, 2));
+    }
+
+    #[test]
+    fn test_decimal_cql_try_from_f64() {
+        let wrapper = DecimalCql::try_from(123.45_f64).unwrap();
+        assert_eq!(*wrapper, Decimal::try_from(123.45_f64).unwrap());
+    }
+
+    #[test]
+    fn test_decimal_cql_try_from_f64_nan_is_parse_error() {
+        let result = DecimalCql::try_from(f64::NAN);
+        assert!(matches!(result, Err(DecimalCqlError::Parse(_))));
+    }
+
     #[test]
     fn test_decimal_cql_deref() {
         let decimal = Decimal::new(12345, 2);
@@ -164,9 +460,25 @@ mod tests {
     #[test]
     fn test_decimal_cql_serialize() {
         let decimal = Decimal::new(12345, 2);
-        let expected_bytes = [
-            0, 0, 0, 20, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 57,
-        ];
+        // length(6) + scale(4) + minimal 2-byte mantissa (0x3039 = 12345)
+        let expected_bytes = [0, 0, 0, 6, 0, 0, 0, 2, 48, 57];
+        let wrapper: DecimalCql = decimal.into();
+        let mut buffer = Vec::new();
+        let writer = CellWriter::new(&mut buffer);
+        wrapper
+            .serialize(&ColumnType::Native(NativeType::Decimal), writer)
+            .unwrap();
+        assert_eq!(
+            buffer, expected_bytes,
+            "Buffer should match expected_bytes exactly"
+        );
+    }
+
+    #[test]
+    fn test_decimal_cql_serialize_negative_minimal_mantissa() {
+        let decimal = Decimal::new(-1, 0);
+        // length(5) + scale(4) + minimal 1-byte mantissa (0xFF = -1)
+        let expected_bytes = [0, 0, 0, 5, 0, 0, 0, 0, 0xFF];
         let wrapper: DecimalCql = decimal.into();
         let mut buffer = Vec::new();
         let writer = CellWriter::new(&mut buffer);
@@ -190,9 +502,24 @@ mod tests {
 
     #[test]
     fn test_extract_scale_and_mantissa_from_slice_success() {
-        let bytes = &[0, 0, 0, 2, 130];
+        // 0x82 has its top bit set, so it sign-extends to -126, not 130.
+        let bytes = &[0, 0, 0, 2, 0x82];
+        let result = extract_scale_and_mantissa_from_slice(bytes).unwrap();
+        assert_eq!(result, (2, -126));
+    }
+
+    #[test]
+    fn test_extract_scale_and_mantissa_from_slice_negative_single_byte() {
+        let bytes = &[0, 0, 0, 0, 0xFF];
+        let result = extract_scale_and_mantissa_from_slice(bytes).unwrap();
+        assert_eq!(result, (0, -1));
+    }
+
+    #[test]
+    fn test_extract_scale_and_mantissa_from_slice_negative_short_mantissa() {
+        let bytes = &[0, 0, 0, 3, 0x80, 0x00];
         let result = extract_scale_and_mantissa_from_slice(bytes).unwrap();
-        assert_eq!(result, (2, 130));
+        assert_eq!(result, (3, -32768));
     }
 
     #[test]
@@ -249,21 +576,17 @@ mod tests {
     }
 
     #[test]
-    fn test_extract_scale_and_mantissa_from_slice_large_mantissa() {
+    fn test_extract_scale_and_mantissa_from_slice_large_mantissa_overflows() {
         let bytes = &[
             0, 0, 0, 2, // Scale (4 bytes)
             0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15,
             0x16, 0x17, 0x18, 0x19, 0x1A,
         ]; // More than 16 bytes
-        let result = extract_scale_and_mantissa_from_slice(bytes).unwrap();
-        assert_eq!(result.0, 2);
-        assert_eq!(
-            result.1,
-            i128::from_be_bytes([
-                0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15,
-                0x16, 0x17
-            ])
-        );
+        let result = extract_scale_and_mantissa_from_slice(bytes);
+        assert!(matches!(
+            result,
+            Err(DecimalCqlError::MantissaOverflow(19))
+        ));
     }
 
     #[test]
@@ -289,4 +612,127 @@ mod tests {
         assert_eq!(result.0, 2);
         assert_eq!(result.1, 0);
     }
+
+    #[test]
+    fn test_rescale_to_supported_range_within_range_is_unchanged() {
+        let result = rescale_to_supported_range(12345, 28, RoundingMode::HalfEven).unwrap();
+        assert_eq!(result, (12345, 28));
+    }
+
+    #[test]
+    fn test_rescale_to_supported_range_half_up() {
+        // scale 30 -> 28 divides by 100; 12350 / 100 = 123.50 -> rounds up to 124
+        let result = rescale_to_supported_range(12350, 30, RoundingMode::HalfUp).unwrap();
+        assert_eq!(result, (124, 28));
+    }
+
+    #[test]
+    fn test_rescale_to_supported_range_half_even_rounds_to_even() {
+        // 1250 / 100 = 12.50 ties to the nearest even quotient, 12
+        let result = rescale_to_supported_range(1250, 30, RoundingMode::HalfEven).unwrap();
+        assert_eq!(result, (12, 28));
+        // 1350 / 100 = 13.50 ties to the nearest even quotient, 14
+        let result = rescale_to_supported_range(1350, 30, RoundingMode::HalfEven).unwrap();
+        assert_eq!(result, (14, 28));
+    }
+
+    #[test]
+    fn test_rescale_to_supported_range_truncate_drops_remainder() {
+        let result = rescale_to_supported_range(-12399, 30, RoundingMode::Truncate).unwrap();
+        assert_eq!(result, (-123, 28));
+    }
+
+    #[test]
+    fn test_rescale_to_supported_range_down_rounds_toward_negative_infinity() {
+        let result = rescale_to_supported_range(-12301, 30, RoundingMode::Down).unwrap();
+        assert_eq!(result, (-124, 28));
+    }
+
+    #[test]
+    fn test_rescale_to_supported_range_divisor_overflow_is_out_of_range() {
+        let result = rescale_to_supported_range(1, i32::MAX, RoundingMode::HalfEven);
+        assert!(matches!(result, Err(DecimalCqlError::ScaleOutOfRange(s)) if s == i32::MAX));
+    }
+
+    #[test]
+    fn test_rescale_to_supported_range_rejects_negative_scale() {
+        let result = rescale_to_supported_range(1, -1, RoundingMode::HalfEven);
+        assert!(matches!(result, Err(DecimalCqlError::ScaleOutOfRange(-1))));
+    }
+
+    #[test]
+    fn test_extract_scale_and_mantissa_from_slice_negative_scale() {
+        // 0xFFFFFFFF as a signed i32 is -1, not 4294967295.
+        let bytes = &[0xFF, 0xFF, 0xFF, 0xFF, 0x01];
+        let result = extract_scale_and_mantissa_from_slice(bytes).unwrap();
+        assert_eq!(result, (-1, 1));
+    }
+
+    #[test]
+    fn test_decimal_cql_value_deserialize_no_frame_is_null() {
+        let result =
+            DecimalCqlValue::deserialize(&ColumnType::Native(NativeType::Decimal), None).unwrap();
+        assert_eq!(result, DecimalCqlValue::Null);
+    }
+
+    #[test]
+    fn test_decimal_cql_value_serialize_null() {
+        let mut buffer = Vec::new();
+        let writer = CellWriter::new(&mut buffer);
+        DecimalCqlValue::Null
+            .serialize(&ColumnType::Native(NativeType::Decimal), writer)
+            .unwrap();
+        // A null cell is encoded as a length of -1.
+        assert_eq!(buffer, [0xFF, 0xFF, 0xFF, 0xFF]);
+    }
+
+    #[test]
+    fn test_decimal_cql_value_serialize_unset() {
+        let mut buffer = Vec::new();
+        let writer = CellWriter::new(&mut buffer);
+        DecimalCqlValue::Unset
+            .serialize(&ColumnType::Native(NativeType::Decimal), writer)
+            .unwrap();
+        // An unset cell is encoded as a length of -2.
+        assert_eq!(buffer, [0xFF, 0xFF, 0xFF, 0xFE]);
+    }
+
+    #[test]
+    fn test_decimal_cql_value_from_option() {
+        let decimal = Decimal::new(1, 0);
+        let wrapper: DecimalCqlValue = Some(decimal.into()).into();
+        assert_eq!(wrapper, DecimalCqlValue::Value(decimal.into()));
+
+        let none_wrapper: DecimalCqlValue = None.into();
+        assert_eq!(none_wrapper, DecimalCqlValue::Null);
+    }
+
+    #[test]
+    fn test_extract_scale_and_mantissa_from_slice_then_rescale_above_28() {
+        // scale 30, mantissa 123456 -> rescaled to scale 28, mantissa 1235 (HalfEven)
+        let bytes = [0u8, 0, 0, 30, 1, 226, 64];
+        let (scale, mantissa) =
+            extract_scale_and_mantissa_from_slice(&bytes).expect("valid bytes");
+        let (rescaled_mantissa, rescaled_scale) =
+            rescale_to_supported_range(mantissa, scale, RoundingMode::default()).unwrap();
+        assert_eq!(rescaled_scale, 28);
+        assert_eq!(rescaled_mantissa, 1235);
+    }
+
+    #[test]
+    fn test_decimal_from_i128_with_scale_beyond_96_bits() {
+        // 2^100 fits in an i128 but overflows Decimal's 96-bit unscaled value.
+        let mantissa: i128 = 1i128 << 100;
+        let result = decimal_from_i128_with_scale(mantissa, 0);
+        assert!(matches!(
+            result,
+            Err(DecimalCqlError::MantissaOutOfDecimalRange(m)) if m == mantissa
+        ));
+    }
+
+    #[test]
+    fn test_decimal_from_i128_with_scale_at_boundary_succeeds() {
+        assert!(decimal_from_i128_with_scale(MAX_DECIMAL_MANTISSA, 0).is_ok());
+        assert!(decimal_from_i128_with_scale(-MAX_DECIMAL_MANTISSA, 0).is_ok());
+    }
 }